@@ -2,31 +2,194 @@ extern crate num;
 extern crate image;
 extern crate crossbeam;
 extern crate num_cpus;
+extern crate rand;
 
 use num::Complex;
 use std::str::FromStr;
 use std::fs::File;
 use std::io::Write;
+use std::path::Path;
 use image::ColorType;
 use image::png::PNGEncoder;
 use num::traits::real::Real;
 use std::thread::spawn;
+use rand::Rng;
 
 
-/// This function aims to determine whether or not the 'c' parameter belongs to the Mandelbrot
-/// set using a limited number of rounds to decide.
+/// This enum lists the escape-time fractal families the tool knows how to render. Each variant
+/// corresponds to a different iteration step applied in 'escape_time'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FractalKind {
+    Mandelbrot,
+    Multibrot3,
+    BurningShip,
+}
+
+impl FromStr for FractalKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mandelbrot" => Ok(FractalKind::Mandelbrot),
+            "multibrot3" => Ok(FractalKind::Multibrot3),
+            "burning-ship" => Ok(FractalKind::BurningShip),
+            _ => Err(format!("Unknown fractal kind '{}'", s)),
+        }
+    }
+}
+
+#[test]
+fn fractal_kind_from_str_test() {
+    assert_eq!(FractalKind::from_str("mandelbrot"), Ok(FractalKind::Mandelbrot));
+    assert_eq!(FractalKind::from_str("multibrot3"), Ok(FractalKind::Multibrot3));
+    assert_eq!(FractalKind::from_str("burning-ship"), Ok(FractalKind::BurningShip));
+    assert!(FractalKind::from_str("nonsense").is_err());
+}
+
+/// This enum lists the rendering modes the tool supports: the default per-pixel escape-time
+/// render, and the Buddhabrot orbit-density render.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RenderMode {
+    EscapeTime,
+    Buddhabrot,
+}
+
+impl FromStr for RenderMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "escape-time" => Ok(RenderMode::EscapeTime),
+            "buddhabrot" => Ok(RenderMode::Buddhabrot),
+            _ => Err(format!("Unknown render mode '{}'", s)),
+        }
+    }
+}
+
+#[test]
+fn render_mode_from_str_test() {
+    assert_eq!(RenderMode::from_str("escape-time"), Ok(RenderMode::EscapeTime));
+    assert_eq!(RenderMode::from_str("buddhabrot"), Ok(RenderMode::Buddhabrot));
+    assert!(RenderMode::from_str("nonsense").is_err());
+}
+
+/// This enum lists the color palettes smooth-colored renders can be mapped through, keyed off
+/// the normalized iteration count 'mu' computed in 'render'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Palette {
+    Grayscale,
+    BlueWhite,
+    Hsv,
+}
+
+impl FromStr for Palette {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "grayscale" => Ok(Palette::Grayscale),
+            "blue-white" => Ok(Palette::BlueWhite),
+            "hsv" => Ok(Palette::Hsv),
+            _ => Err(format!("Unknown palette '{}'", s)),
+        }
+    }
+}
+
+#[test]
+fn palette_from_str_test() {
+    assert_eq!(Palette::from_str("grayscale"), Ok(Palette::Grayscale));
+    assert_eq!(Palette::from_str("blue-white"), Ok(Palette::BlueWhite));
+    assert_eq!(Palette::from_str("hsv"), Ok(Palette::Hsv));
+    assert!(Palette::from_str("nonsense").is_err());
+}
+
+/// Converts an HSV triple (hue in degrees, saturation and value in 0.0..=1.0) to an RGB triple.
+fn hsv_to_rgb(hue: f64, saturation: f64, value: f64) -> [u8; 3] {
+    let c = value * saturation;
+    let h = (hue % 360.0) / 60.0;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = value - c;
+
+    [
+        ((r + m) * 255.0) as u8,
+        ((g + m) * 255.0) as u8,
+        ((b + m) * 255.0) as u8,
+    ]
+}
+
+#[test]
+fn hsv_to_rgb_test() {
+    assert_eq!(hsv_to_rgb(0.0, 1.0, 1.0), [255, 0, 0]);
+    assert_eq!(hsv_to_rgb(120.0, 1.0, 1.0), [0, 255, 0]);
+    assert_eq!(hsv_to_rgb(240.0, 1.0, 1.0), [0, 0, 255]);
+    assert_eq!(hsv_to_rgb(0.0, 0.0, 1.0), [255, 255, 255]);
+}
+
+/// Maps a normalized iteration count 'mu' (as computed in 'render') through 'palette' to an
+/// RGB triple. 'limit' is the iteration cap used to bring 'mu' into the 0.0..=1.0 range.
+fn color_for_mu(mu: f64, limit: u32, palette: Palette) -> [u8; 3] {
+    let t = (mu / limit as f64).max(0.0).min(1.0);
+
+    match palette {
+        Palette::Grayscale => {
+            let v = (t * 255.0) as u8;
+            [v, v, v]
+        },
+        Palette::BlueWhite => {
+            [
+                (t * 255.0) as u8,
+                (t * 255.0) as u8,
+                (80.0 + t * 175.0) as u8,
+            ]
+        },
+        Palette::Hsv => hsv_to_rgb(t * 360.0, 1.0, 1.0),
+    }
+}
+
+#[test]
+fn color_for_mu_test() {
+    assert_eq!(color_for_mu(0.0, 255, Palette::Grayscale), [0, 0, 0]);
+    assert_eq!(color_for_mu(255.0, 255, Palette::Grayscale), [255, 255, 255]);
+    assert_eq!(color_for_mu(0.0, 255, Palette::BlueWhite), [0, 0, 80]);
+    assert_eq!(color_for_mu(255.0, 255, Palette::BlueWhite), [255, 255, 255]);
+}
+
+/// This function aims to determine whether or not the 'c' parameter belongs to the fractal set
+/// identified by 'kind' using a limited number of rounds to decide.
 ///
-/// If 'c' is not an element of the Mandelbrot set the function will return Some(i) where i
-/// corresponds to the round from which the norm of complex number z is greater than or equal to 2
+/// If 'c' is not an element of the set the function will return Some((i, z)) where i corresponds
+/// to the round from which the norm of complex number z became greater than 2 and z
+/// is the orbit's value a couple of iterations past that point (needed for smooth coloring),
 /// otherwise it will return None.
-fn escape_time(c: Complex<f64>, limit: u32) -> Option<u32> {
+fn escape_time(c: Complex<f64>, limit: u32, kind: FractalKind) -> Option<(u32, Complex<f64>)> {
     let mut z = Complex {re: 0.0, im: 0.0};
 
+    let step = |z: Complex<f64>| match kind {
+        FractalKind::Mandelbrot => z * z + c,
+        FractalKind::Multibrot3 => z * z * z + c,
+        FractalKind::BurningShip => {
+            let folded = Complex {re: z.re.abs(), im: z.im.abs()};
+            folded * folded + c
+        },
+    };
+
     for i in 0..limit {
-        z = z * z + c;
+        z = step(z);
 
         if z.norm_sqr() > 4.0 {
-            return Some(i);
+            for _ in 0..2 {
+                z = step(z);
+            }
+
+            return Some((i, z));
         }
     }
 
@@ -107,18 +270,174 @@ fn pixel_to_complex_point_test() {
     ), Complex { re: -0.5, im: -0.5})
 }
 
-/// Filling an array of pixels to represent a Mandelbrot rectangle.
+/// The inverse of 'pixel_to_complex_point': given a point in the complex plane, returns the
+/// pixel of the output image it falls into, or None if the point lies outside 'edges'.
+fn complex_point_to_pixel(
+    edges: (usize, usize),
+    point: Complex<f64>,
+    super_left: Complex<f64>,
+    infer_right: Complex<f64>) -> Option<(usize, usize)> {
+
+    let (width, height) = (infer_right.re - super_left.re, super_left.im - infer_right.im);
+
+    let column = (point.re - super_left.re) / width * edges.0 as f64;
+    let row = (super_left.im - point.im) / height * edges.1 as f64;
+
+    if column < 0.0 || row < 0.0 || column >= edges.0 as f64 || row >= edges.1 as f64 {
+        None
+    } else {
+        Some((column as usize, row as usize))
+    }
+}
+
+#[test]
+fn complex_point_to_pixel_test() {
+    assert_eq!(complex_point_to_pixel(
+        (100, 100),
+        Complex { re: -0.5, im: -0.5 },
+        Complex { re: -1.0, im: 1.0},
+        Complex { re: 1.0, im: -1.0}
+    ), Some((25, 75)));
+
+    assert_eq!(complex_point_to_pixel(
+        (100, 100),
+        Complex { re: -5.0, im: -0.5 },
+        Complex { re: -1.0, im: 1.0},
+        Complex { re: 1.0, im: -1.0}
+    ), None);
+}
+
+/// Samples 'samples' random points of the complex plane bounded by 'super_left'/'infer_right',
+/// keeps only the ones whose Mandelbrot orbit escapes within 'limit' iterations, then replays
+/// each escaping orbit and increments a histogram bin for every visited point that falls back
+/// into the image. Points that never escape contribute nothing, which is why the orbit has to
+/// be iterated twice: once to test escape, and — only for the survivors — a second time to
+/// accumulate it.
+///
+/// Returns a per-thread histogram of size 'edges.0 * edges.1', meant to be summed with the
+/// histograms produced by the other worker threads.
+fn accumulate_buddhabrot_orbits(
+    edges: (usize, usize),
+    super_left: Complex<f64>,
+    infer_right: Complex<f64>,
+    samples: u32,
+    limit: u32
+) -> Vec<u32> {
+    let mut histogram = vec![0u32; edges.0 * edges.1];
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..samples {
+        let c = Complex {
+            re: rng.gen_range(super_left.re, infer_right.re),
+            im: rng.gen_range(infer_right.im, super_left.im),
+        };
+
+        let mut z = Complex {re: 0.0, im: 0.0};
+        let mut escapes = false;
+
+        for _ in 0..limit {
+            z = z * z + c;
+
+            if z.norm_sqr() > 4.0 {
+                escapes = true;
+                break;
+            }
+        }
+
+        if !escapes {
+            continue;
+        }
+
+        let mut z = Complex {re: 0.0, im: 0.0};
+
+        for _ in 0..limit {
+            z = z * z + c;
+
+            if z.norm_sqr() > 4.0 {
+                break;
+            }
+
+            if let Some((column, row)) = complex_point_to_pixel(edges, z, super_left, infer_right) {
+                histogram[row * edges.0 + column] += 1;
+            }
+        }
+    }
+
+    histogram
+}
+
+/// Renders a Buddhabrot view of the region bounded by 'super_left'/'infer_right' into an RGB
+/// pixel buffer, spreading the orbit samples across 'num_cpus::get()' worker threads that each
+/// take 'samples / cpus + 1' of them (so slightly more than 'samples' in total), each
+/// accumulating into its own histogram that gets summed once all threads join.
+/// The summed histogram is then log-normalized to the 0-255 range and replicated across the
+/// three color channels so the result can be saved through the same PNG path as the per-pixel
+/// renderer.
+fn render_buddhabrot(
+    edges: (usize, usize),
+    super_left: Complex<f64>,
+    infer_right: Complex<f64>,
+    samples: u32,
+    limit: u32
+) -> Vec<u8> {
+    let cpus = num_cpus::get();
+    let samples_per_thread = samples / cpus as u32 + 1;
+
+    let histograms: Vec<Vec<u32>> = crossbeam::scope(|spawner| {
+        (0..cpus)
+            .map(|_| spawner.spawn(move || {
+                accumulate_buddhabrot_orbits(edges, super_left, infer_right, samples_per_thread, limit)
+            }))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join())
+            .collect()
+    });
+
+    let mut histogram = vec![0u32; edges.0 * edges.1];
+
+    for thread_histogram in histograms {
+        for (total, count) in histogram.iter_mut().zip(thread_histogram.into_iter()) {
+            *total += count;
+        }
+    }
+
+    let max = *histogram.iter().max().unwrap_or(&0);
+    let mut pixels = vec![0u8; edges.0 * edges.1 * 3];
+
+    for (i, &count) in histogram.iter().enumerate() {
+        let byte = if max == 0 {
+            0
+        } else {
+            (((1.0 + count as f64).ln() / (1.0 + max as f64).ln()) * 255.0) as u8
+        };
+
+        let offset = i * 3;
+        pixels[offset] = byte;
+        pixels[offset + 1] = byte;
+        pixels[offset + 2] = byte;
+    }
+
+    pixels
+}
+
+/// Filling an array of pixels to represent a Mandelbrot rectangle, 3 bytes (RGB) per pixel.
 ///
 /// 'edges' is a parameter to indicate the width and the height of the output image.
 /// 'super_left' and 'infer_right' correspond respectively to the top left corner and the bottom
-/// right corner of the output image.
+/// right corner of the output image. Escaping points are colored smoothly through 'palette';
+/// interior points (those that never escape) stay black.
 fn render(
     pixels: &mut [u8],
     edges: (usize, usize),
     super_left: Complex<f64>,
-    infer_right: Complex<f64>
+    infer_right: Complex<f64>,
+    kind: FractalKind,
+    palette: Palette
 ) {
-    assert_eq!(pixels.len(), edges.0 * edges.1);
+    let limit = 255;
+
+    assert_eq!(pixels.len(), edges.0 * edges.1 * 3);
 
     for row in 0..edges.1 {
         for column in 0..edges.0 {
@@ -129,41 +448,182 @@ fn render(
                 infer_right
             );
 
-            pixels[row * edges.0 + column] = match escape_time(point, 255) {
-                None => 0,
-                Some(count) => 255 - count as u8,
-            }
+            let rgb = match escape_time(point, limit, kind) {
+                None => [0, 0, 0],
+                Some((count, z)) => {
+                    let mu = count as f64 + 1.0 - (0.5 * z.norm_sqr().ln()).ln() / 2.0f64.ln();
+                    color_for_mu(mu, limit, palette)
+                },
+            };
+
+            let offset = (row * edges.0 + column) * 3;
+            pixels[offset..offset + 3].copy_from_slice(&rgb);
+        }
+    }
+}
+
+
+/// Renders the whole image into 'pixels' using a dynamic tile scheduler instead of fixed equal
+/// row strips: the image is cut into 'TILE_ROWS'-high bands held in a shared queue, and each of
+/// 'num_cpus::get()' worker threads pulls the next tile as soon as it finishes its current one.
+/// This keeps threads that land on cheap, fast-escaping tiles busy instead of idle, which matters
+/// because interior rows (those that never escape) run the full iteration 'limit' while exterior
+/// rows bail out quickly, so a fixed split leaves some threads far more loaded than others.
+fn render_tiled(
+    pixels: &mut [u8],
+    edges: (usize, usize),
+    super_left: Complex<f64>,
+    infer_right: Complex<f64>,
+    kind: FractalKind,
+    palette: Palette
+) {
+    const TILE_ROWS: usize = 64;
+
+    let row_bytes = edges.0 * 3;
+
+    let tiles: Vec<(usize, &mut [u8])> = pixels.chunks_mut(TILE_ROWS * row_bytes)
+        .enumerate()
+        .map(|(i, chunk)| (TILE_ROWS * i, chunk))
+        .collect();
+
+    let queue = std::sync::Mutex::new(tiles);
+
+    crossbeam::scope(|spawner| {
+        for _ in 0..num_cpus::get() {
+            let queue = &queue;
+
+            spawner.spawn(move || {
+                loop {
+                    let tile = queue.lock().unwrap().pop();
+
+                    let (top, chunk) = match tile {
+                        Some(tile) => tile,
+                        None => break,
+                    };
+
+                    let height = chunk.len() / row_bytes;
+                    let chunk_shape = (edges.0, height);
+                    let chunk_supl = pixel_to_complex_point(
+                        edges,
+                        (0, top),
+                        super_left,
+                        infer_right
+                    );
+                    let chunk_infr = pixel_to_complex_point(
+                        edges,
+                        (edges.0, top + height),
+                        super_left,
+                        infer_right
+                    );
+
+                    render(chunk, chunk_shape, chunk_supl, chunk_infr, kind, palette);
+                }
+            });
+        }
+    });
+}
+
+/// This enum lists the output file formats 'save_mandelbrot_rectangle' can write to, picked from
+/// the output file name's extension by 'OutputFormat::from_file_name'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Png,
+    Pgm,
+    Ppm,
+}
+
+impl OutputFormat {
+    fn from_file_name(file_name: &str) -> Result<OutputFormat, String> {
+        match Path::new(file_name).extension().and_then(|ext| ext.to_str()) {
+            Some("png") => Ok(OutputFormat::Png),
+            Some("pgm") => Ok(OutputFormat::Pgm),
+            Some("ppm") => Ok(OutputFormat::Ppm),
+            Some(ext) => Err(format!("Unsupported output file extension '.{}'", ext)),
+            None => Err(format!("Output file name '{}' has no extension", file_name)),
         }
     }
 }
 
+#[test]
+fn output_format_from_file_name_test() {
+    assert_eq!(OutputFormat::from_file_name("out.png"), Ok(OutputFormat::Png));
+    assert_eq!(OutputFormat::from_file_name("out.pgm"), Ok(OutputFormat::Pgm));
+    assert_eq!(OutputFormat::from_file_name("out.ppm"), Ok(OutputFormat::Ppm));
+    assert!(OutputFormat::from_file_name("out.bmp").is_err());
+    assert!(OutputFormat::from_file_name("out").is_err());
+}
+
+/// Averages the three channels of an RGB pixel buffer down to a single-channel grayscale buffer,
+/// used when an RGB render is saved as a PGM.
+fn rgb_to_gray(pixels: &[u8]) -> Vec<u8> {
+    pixels.chunks_exact(3)
+        .map(|rgb| ((rgb[0] as u32 + rgb[1] as u32 + rgb[2] as u32) / 3) as u8)
+        .collect()
+}
+
+#[test]
+fn rgb_to_gray_test() {
+    assert_eq!(rgb_to_gray(&[30, 60, 90]), vec![60]);
+}
+
+/// Writes 'pixels' as a binary netpbm file ('magic' is "P5" for grayscale or "P6" for RGB)
+/// directly to 'file_name', without pulling in a codec: just the header followed by the raw
+/// samples.
+fn save_mandelbrot_rectangle_as_pnm(
+    file_name: &str,
+    pixels: &[u8],
+    edges: (usize, usize),
+    magic: &str
+) -> Result<(), std::io::Error> {
+    let mut output_file = File::create(file_name)?;
+    write!(output_file, "{}\n{} {}\n255\n", magic, edges.0, edges.1)?;
+    output_file.write_all(pixels)?;
+
+    Ok(())
+}
 
 /// This function stores a Mandelbrot rectangle contained in 'pixels' of resolution 'edges' in a
-/// file named 'file_name'.
-fn save_mandelbrot_rectangle_as_png(
+/// file named 'file_name', in PNG, PGM or PPM, picked from 'file_name's extension.
+fn save_mandelbrot_rectangle(
     file_name: &str,
     pixels: &[u8],
     edges: (usize, usize)
 ) -> Result<(), std::io::Error> {
-    let output_file = File::create(file_name)?;
-    let encoder = PNGEncoder::new(output_file);
-    encoder.encode(
-        &pixels,
-        edges.0 as u32,
-        edges.1 as u32,
-        ColorType::Gray(8)
-    )?;
+    match OutputFormat::from_file_name(file_name) {
+        Ok(OutputFormat::Png) => {
+            let output_file = File::create(file_name)?;
+            let encoder = PNGEncoder::new(output_file);
+            encoder.encode(
+                &pixels,
+                edges.0 as u32,
+                edges.1 as u32,
+                ColorType::RGB(8)
+            )
+        },
+        Ok(OutputFormat::Ppm) => save_mandelbrot_rectangle_as_pnm(file_name, pixels, edges, "P6"),
+        Ok(OutputFormat::Pgm) => {
+            let gray = rgb_to_gray(pixels);
+            save_mandelbrot_rectangle_as_pnm(file_name, &gray, edges, "P5")
+        },
+        Err(message) => Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, message)),
+    }
+}
 
-    Ok(())
+fn print_usage_and_exit(program: &str) -> ! {
+    writeln!(std::io::stderr(), "Usage: mandelbrot FILE_NAME PIXELS SUP_LEFT INFER_RIGHT KIND PALETTE [MODE [SAMPLES LIMIT]]").unwrap();
+    writeln!(std::io::stderr(), "Example: {} mandelbrot.png 1000x750 -1.20,0.60 -1,0.20 mandelbrot hsv", program).unwrap();
+    writeln!(std::io::stderr(), "Example: {} buddhabrot.png 1000x750 -1.20,0.60 -1,0.20 mandelbrot hsv buddhabrot 5000000 1000", program).unwrap();
+    writeln!(std::io::stderr(), "KIND is one of: mandelbrot, multibrot3, burning-ship").unwrap();
+    writeln!(std::io::stderr(), "PALETTE is one of: grayscale, blue-white, hsv").unwrap();
+    writeln!(std::io::stderr(), "MODE is one of: escape-time (default), buddhabrot").unwrap();
+    std::process::exit(1);
 }
 
 fn main() {
     let args: Vec<String> = std::env::args().collect();
 
-    if args.len() != 5 {
-        writeln!(std::io::stderr(), "Usage: mandelbrot FILE_NAME PIXELS SUP_LEFT INFER_RIGHT").unwrap();
-        writeln!(std::io::stderr(), "Example: {} mandelbrot.png 1000x750 -1.20,0.60 -1,0.20", args[0]).unwrap();
-        std::process::exit(1);
+    if args.len() != 7 && args.len() != 8 && args.len() != 10 {
+        print_usage_and_exit(&args[0]);
     }
 
     let edges = pair_analyze(&args[2], 'x')
@@ -174,40 +634,40 @@ fn main() {
     let infer_right = complex_pair_analyze(&args[4])
         .expect("Incorrect format for the bottom right corner complex point");
 
-    let mut pixels = vec![0; edges.0 * edges.1];
+    let kind = FractalKind::from_str(&args[5])
+        .expect("Incorrect value for the fractal kind");
 
-    let cpus = num_cpus::get();
-    let rows_per_chunk = edges.1 / cpus + 1;
-
-    {
-        let chunks: Vec<&mut [u8]> = pixels.chunks_exact_mut(rows_per_chunk * edges.0)
-            .collect();
-
-        crossbeam::scope(|spawner| {
-           for (i, chunk) in chunks.into_iter().enumerate() {
-               let top = rows_per_chunk * i;
-               let height = chunk.len() / edges.0;
-               let chunk_shape = (edges.0, height);
-               let chunk_supl = pixel_to_complex_point(
-                   edges,
-                   (0, top),
-                   super_left,
-                   infer_right
-               );
-               let chunk_infr = pixel_to_complex_point(
-                   edges,
-                   (edges.0, top + height),
-                   super_left,
-                   infer_right
-               );
-
-               spawner.spawn(move || {
-                   render(chunk, chunk_shape, chunk_supl, chunk_infr);
-               });
-           }
-        });
-    }
-
-    save_mandelbrot_rectangle_as_png(&args[1], &pixels, edges).expect("An error \
+    let palette = Palette::from_str(&args[6])
+        .expect("Incorrect value for the palette");
+
+    let mode = if args.len() >= 8 {
+        RenderMode::from_str(&args[7]).expect("Incorrect value for the render mode")
+    } else {
+        RenderMode::EscapeTime
+    };
+
+    if mode == RenderMode::Buddhabrot && args.len() != 10 {
+        print_usage_and_exit(&args[0]);
+    }
+
+    let pixels = match mode {
+        RenderMode::EscapeTime => {
+            let mut pixels = vec![0; edges.0 * edges.1 * 3];
+
+            render_tiled(&mut pixels, edges, super_left, infer_right, kind, palette);
+
+            pixels
+        },
+        RenderMode::Buddhabrot => {
+            let samples: u32 = args[8].parse()
+                .expect("Incorrect value for the sample count");
+            let limit: u32 = args[9].parse()
+                .expect("Incorrect value for the iteration limit");
+
+            render_buddhabrot(edges, super_left, infer_right, samples, limit)
+        },
+    };
+
+    save_mandelbrot_rectangle(&args[1], &pixels, edges).expect("An error \
         occured while trying to save the mandelbrot rectangle");
 }
\ No newline at end of file